@@ -0,0 +1,132 @@
+//! TOML configuration for the CT service, resolved from a CLI arg or an
+//! environment variable, falling back to built-in defaults when absent.
+
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::CT_SERVICE_KEY_ID;
+
+const CONFIG_PATH_ENV_VAR: &str = "CT_SERVICE_CONFIG";
+const DEFAULT_CONFIG_PATH: &str = "ct_service.toml";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ServiceConfig {
+    pub operators: Vec<String>,
+    pub poll_interval_secs: u64,
+    /// Explicit log-list URL, overriding the default Google `all_logs_list.json`.
+    pub log_list_url: Option<String>,
+    pub log_list_cache_secs: u64,
+    pub webserver: WebServerSettings,
+    pub storage: StorageConfig,
+    pub keystore_key_id: String,
+    /// `RUST_LOG` filter directive string, applied before the logger is
+    /// initialized.
+    pub log_filter: String,
+    /// Domains to watch for in logged certificates, passed to
+    /// [`crate::watchlist::Watchlist::new`]. Exact matches and subdomains of
+    /// a listed domain both count as a match.
+    pub watched_domains: Vec<String>,
+}
+
+impl ServiceConfig {
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval_secs)
+    }
+
+    pub fn log_list_cache_duration(&self) -> Duration {
+        Duration::from_secs(self.log_list_cache_secs)
+    }
+}
+
+impl Default for ServiceConfig {
+    fn default() -> Self {
+        Self {
+            operators: vec![
+                "Google".to_string(),
+                "Cloudflare".to_string(),
+                "DigiCert".to_string(),
+                "Sectigo".to_string(),
+                "Let's Encrypt".to_string(),
+            ],
+            poll_interval_secs: 60,
+            log_list_url: None,
+            log_list_cache_secs: 60 * 60 * 24,
+            webserver: WebServerSettings::default(),
+            storage: StorageConfig::default(),
+            keystore_key_id: CT_SERVICE_KEY_ID.to_string(),
+            log_filter: "DEBUG,ctclient::internal=off,reqwest=off,hyper=off,tracing=off,\
+                sp1_stark=info,jmt=off,p3_dft=off,p3_fri=off,sp1_core_executor=info,\
+                sp1_recursion_program=info,p3_merkle_tree=off,sp1_recursion_compiler=off,\
+                sp1_core_machine=off"
+                .to_string(),
+            watched_domains: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebServerSettings {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+}
+
+impl Default for WebServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            host: "127.0.0.1".to_string(),
+            port: 50524,
+        }
+    }
+}
+
+/// Selects which [`crate::monitor_store::MonitorStore`] backend to
+/// construct.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum StorageConfig {
+    Memory,
+    Redb { path: String },
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        StorageConfig::Redb {
+            path: "ct_monitor_store.redb".to_string(),
+        }
+    }
+}
+
+/// Loads the service config from the path given as the first CLI argument,
+/// then the `CT_SERVICE_CONFIG` env var, then `./ct_service.toml` if it
+/// exists. Falls back to [`ServiceConfig::default`] when none of those are
+/// present.
+pub fn load() -> Result<ServiceConfig> {
+    match resolve_config_path() {
+        Some(path) => {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("Error reading config file {}", path.display()))?;
+            toml::from_str(&contents)
+                .with_context(|| format!("Error parsing config file {}", path.display()))
+        }
+        None => Ok(ServiceConfig::default()),
+    }
+}
+
+fn resolve_config_path() -> Option<PathBuf> {
+    if let Some(arg_path) = env::args().nth(1) {
+        return Some(PathBuf::from(arg_path));
+    }
+
+    if let Ok(env_path) = env::var(CONFIG_PATH_ENV_VAR) {
+        return Some(PathBuf::from(env_path));
+    }
+
+    let default_path = PathBuf::from(DEFAULT_CONFIG_PATH);
+    default_path.exists().then_some(default_path)
+}
@@ -0,0 +1,238 @@
+//! RFC 6962 §2.1.2 consistency proof verification.
+//!
+//! `watch_log` uses this to make sure a new STH is an append-only extension
+//! of the last STH we persisted, rather than accepting any STH from
+//! `CTClient::update` whose root hash differs.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use anyhow::{anyhow, bail, Result};
+
+fn hash_children(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Verifies that the tree of size `n` with root `r2` is a consistent,
+/// append-only extension of the tree of size `m` with root `r1`, given the
+/// `get-sth-consistency` proof nodes between them.
+pub fn verify_consistency_proof(
+    m: u64,
+    n: u64,
+    proof: &[[u8; 32]],
+    r1: [u8; 32],
+    r2: [u8; 32],
+) -> bool {
+    if m == n {
+        return proof.is_empty() && r1 == r2;
+    }
+    if m == 0 {
+        return true;
+    }
+    if proof.is_empty() {
+        return false;
+    }
+
+    let mut proof = proof.iter();
+    let mut node = m - 1;
+    let mut last = n - 1;
+    while node % 2 == 1 {
+        node >>= 1;
+        last >>= 1;
+    }
+
+    let (mut fr, mut sr) = if node > 0 {
+        let Some(c) = proof.next() else {
+            return false;
+        };
+        (*c, *c)
+    } else {
+        (r1, r1)
+    };
+
+    while node > 0 {
+        if node % 2 == 1 {
+            let Some(c) = proof.next() else {
+                return false;
+            };
+            fr = hash_children(c, &fr);
+            sr = hash_children(c, &sr);
+        } else if node < last {
+            let Some(c) = proof.next() else {
+                return false;
+            };
+            sr = hash_children(&sr, c);
+        }
+        node >>= 1;
+        last >>= 1;
+    }
+
+    while last > 0 {
+        let Some(c) = proof.next() else {
+            return false;
+        };
+        sr = hash_children(&sr, c);
+        last >>= 1;
+    }
+
+    fr == r1 && sr == r2
+}
+
+#[derive(Debug, Deserialize)]
+struct ConsistencyProofResponse {
+    consistency: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SthResponse {
+    tree_size: u64,
+}
+
+/// Fetches the current tree size from a CT log's `get-sth` endpoint, so a
+/// freshly restarted `watch_log` can tell whether its persisted checkpoint
+/// is behind the log's tip and needs to backfill entries.
+pub async fn fetch_latest_tree_size(log_url: &str) -> Result<u64> {
+    let url = format!("{}ct/v1/get-sth", log_url);
+    let response = reqwest::get(&url).await?.error_for_status()?;
+    let body: SthResponse = response.json().await?;
+    Ok(body.tree_size)
+}
+
+#[derive(Debug, Deserialize)]
+struct EntriesResponse {
+    entries: Vec<EntryResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntryResponse {
+    leaf_input: String,
+}
+
+/// Fetches the raw `MerkleTreeLeaf` bytes (RFC 6962 §4.6 `get-entries`) for
+/// the half-open range `[start, end)`, paging through the log's own
+/// per-request cap rather than assuming it will return everything at once.
+pub async fn fetch_entries(log_url: &str, start: u64, end: u64) -> Result<Vec<(u64, Vec<u8>)>> {
+    let mut entries = Vec::new();
+    let mut next = start;
+
+    while next < end {
+        let url = format!(
+            "{}ct/v1/get-entries?start={}&end={}",
+            log_url,
+            next,
+            end - 1
+        );
+        let response = reqwest::get(&url).await?.error_for_status()?;
+        let body: EntriesResponse = response.json().await?;
+
+        if body.entries.is_empty() {
+            bail!(
+                "log returned no entries for range [{}, {})",
+                next,
+                end
+            );
+        }
+
+        for entry in &body.entries {
+            let leaf_input = BASE64.decode(&entry.leaf_input)?;
+            entries.push((next, leaf_input));
+            next += 1;
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Fetches the `get-sth-consistency` proof nodes between tree sizes `first`
+/// and `second` from a CT log's base URL.
+pub async fn fetch_consistency_proof(
+    log_url: &str,
+    first: u64,
+    second: u64,
+) -> Result<Vec<[u8; 32]>> {
+    let url = format!(
+        "{}ct/v1/get-sth-consistency?first={}&second={}",
+        log_url, first, second
+    );
+
+    let response = reqwest::get(&url).await?.error_for_status()?;
+    let body: ConsistencyProofResponse = response.json().await?;
+
+    body.consistency
+        .iter()
+        .map(|node| {
+            let bytes = BASE64.decode(node)?;
+            let array: [u8; 32] = bytes
+                .try_into()
+                .map_err(|_| anyhow!("consistency proof node is not 32 bytes"))?;
+            Ok(array)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_old_tree_is_always_consistent() {
+        let r2 = [7u8; 32];
+        assert!(verify_consistency_proof(0, 5, &[], [0u8; 32], r2));
+    }
+
+    #[test]
+    fn equal_sizes_require_matching_roots_and_no_proof() {
+        let r = [3u8; 32];
+        assert!(verify_consistency_proof(4, 4, &[], r, r));
+        assert!(!verify_consistency_proof(4, 4, &[], r, [4u8; 32]));
+        assert!(!verify_consistency_proof(4, 4, &[[1u8; 32]], r, r));
+    }
+
+    #[test]
+    fn single_leaf_growth_is_consistent() {
+        // m=1 -> n=2: the new tree is just hash(old_leaf, new_leaf).
+        let leaf_a = [1u8; 32];
+        let leaf_b = [2u8; 32];
+        let r1 = leaf_a;
+        let r2 = hash_children(&leaf_a, &leaf_b);
+
+        assert!(verify_consistency_proof(1, 2, &[leaf_b], r1, r2));
+        assert!(!verify_consistency_proof(1, 2, &[leaf_a], r1, r2));
+    }
+
+    fn hex32(s: &str) -> [u8; 32] {
+        let bytes = (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect::<Vec<u8>>();
+        bytes.try_into().unwrap()
+    }
+
+    #[test]
+    fn consistency_proof_folds_multiple_bits_of_both_branches() {
+        // A hand-built RFC 6962 tree over 10 leaves `leaf_hash("leaf{i}")`
+        // for i in 0..10, checking the proof between m=6 and n=10: m-1=5 has
+        // its low bit set (exercises the `node % 2 == 1` fold) and, once
+        // shifted, stays below `last` (exercises the `node < last` fold) —
+        // unlike m=1 where `node` starts at 0 and neither branch runs.
+        let r1 = hex32("2bec773a6ce6d83151210fdd24bea43e7c4c94902811ce6124a21c71951860bd");
+        let r2 = hex32("477d9e56c60e211bc1f0b6dbfe3633ae8111c636bc107038feb98480128e60b6");
+        let proof = vec![
+            hex32("04dce8407036aee2c73aa6527387ae71a36257934b17a77b006b95a42a5be0e1"),
+            hex32("515351d56b565b5ec878c6b8959f42cf7f587e3bbb414e8aecf158ea7822e9ad"),
+            hex32("86f9ec25a8a2b32a4bd733e04c213de63c8b0655bcb887b75cfd8b02691be0e5"),
+            hex32("acef5080e79ef8d49a8a4832f4e23e58dc7d1ac8f201554c60b656b714dbc03b"),
+        ];
+
+        assert!(verify_consistency_proof(6, 10, &proof, r1, r2));
+        // Corrupting any single proof node should break verification.
+        let mut bad_proof = proof.clone();
+        bad_proof[0] = [0u8; 32];
+        assert!(!verify_consistency_proof(6, 10, &bad_proof, r1, r2));
+    }
+}
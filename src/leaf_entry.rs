@@ -0,0 +1,213 @@
+//! Parses the raw `MerkleTreeLeaf` bytes handed to `CTClient::update`'s
+//! per-entry callback (RFC 6962 §3.4) and extracts the end-entity / precert
+//! names so they can be matched against a [`crate::watchlist::Watchlist`].
+
+use anyhow::{anyhow, bail, Result};
+use x509_parser::certificate::{TbsCertificate, X509Certificate};
+use x509_parser::extensions::GeneralName;
+use x509_parser::nom::Err as NomErr;
+use x509_parser::prelude::FromDer;
+
+const ENTRY_TYPE_X509: u16 = 0;
+const ENTRY_TYPE_PRECERT: u16 = 1;
+
+/// Names found on a single logged certificate or precertificate.
+#[derive(Debug, Clone, Default)]
+pub struct LeafCertNames {
+    pub common_name: Option<String>,
+    pub sans: Vec<String>,
+}
+
+impl LeafCertNames {
+    pub fn all_names(&self) -> impl Iterator<Item = &str> {
+        self.common_name.as_deref().into_iter().chain(self.sans.iter().map(String::as_str))
+    }
+}
+
+/// Extracts the CN/SANs from a `MerkleTreeLeaf`'s `TimestampedEntry`.
+pub fn extract_names(leaf_input: &[u8]) -> Result<LeafCertNames> {
+    // MerkleTreeLeaf: version(1) + leaf_type(1) + timestamp(8) + entry_type(2)
+    if leaf_input.len() < 12 {
+        bail!("leaf input too short to contain a TimestampedEntry");
+    }
+    let entry_type = u16::from_be_bytes([leaf_input[10], leaf_input[11]]);
+    let rest = &leaf_input[12..];
+
+    let cert_der = match entry_type {
+        ENTRY_TYPE_X509 => read_opaque24(rest)?.0,
+        ENTRY_TYPE_PRECERT => {
+            // PreCert: issuer_key_hash[32] + tbs_certificate<1..2^24-1>
+            if rest.len() < 32 {
+                bail!("precert entry too short to contain an issuer key hash");
+            }
+            read_opaque24(&rest[32..])?.0
+        }
+        other => bail!("unrecognized log entry type {}", other),
+    };
+
+    names_from_der(entry_type, cert_der)
+}
+
+/// Reads a TLS `opaque<1..2^24-1>` length-prefixed field and returns
+/// `(field, remainder)`.
+fn read_opaque24(buf: &[u8]) -> Result<(&[u8], &[u8])> {
+    if buf.len() < 3 {
+        bail!("truncated length-prefixed field");
+    }
+    let len = ((buf[0] as usize) << 16) | ((buf[1] as usize) << 8) | buf[2] as usize;
+    let buf = &buf[3..];
+    if buf.len() < len {
+        bail!("truncated length-prefixed field");
+    }
+    Ok((&buf[..len], &buf[len..]))
+}
+
+fn names_from_der(entry_type: u16, der: &[u8]) -> Result<LeafCertNames> {
+    if entry_type == ENTRY_TYPE_X509 {
+        let (_, cert) =
+            X509Certificate::from_der(der).map_err(|e| anyhow!("Error parsing leaf cert: {}", map_nom_err(e)))?;
+        return Ok(names_from_tbs(cert.tbs_certificate));
+    }
+
+    // A precert's signed_entry is a bare TBSCertificate, not a full
+    // Certificate, so it has to be parsed on its own.
+    let (_, tbs) = TbsCertificate::from_der(der)
+        .map_err(|e| anyhow!("Error parsing precert TBSCertificate: {}", map_nom_err(e)))?;
+    Ok(names_from_tbs(tbs))
+}
+
+fn map_nom_err<E: std::fmt::Display>(e: NomErr<E>) -> String {
+    e.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // DER of a self-signed P-256 end-entity cert for `CN=example.com` with
+    // SANs `example.com` / `www.example.com`, generated with:
+    //   openssl req -x509 -newkey ec -pkeyopt ec_paramgen_curve:P-256 \
+    //     -keyout key.pem -out cert.pem -days 1 -nodes \
+    //     -subj "/CN=example.com" \
+    //     -addext "subjectAltName=DNS:example.com,DNS:www.example.com"
+    const X509_LEAF_CERT_DER: &[u8] = &[
+        48, 130, 1, 119, 48, 130, 1, 30, 160, 3, 2, 1, 2, 2, 20, 68, 188, 218, 95, 207, 211, 51,
+        164, 111, 3, 214, 133, 85, 105, 179, 185, 219, 148, 204, 34, 48, 10, 6, 8, 42, 134, 72,
+        206, 61, 4, 3, 2, 48, 22, 49, 20, 48, 18, 6, 3, 85, 4, 3, 12, 11, 101, 120, 97, 109, 112,
+        108, 101, 46, 99, 111, 109, 48, 30, 23, 13, 50, 54, 48, 55, 51, 48, 49, 54, 51, 53, 51,
+        56, 90, 23, 13, 50, 54, 48, 55, 51, 49, 49, 54, 51, 53, 51, 56, 90, 48, 22, 49, 20, 48,
+        18, 6, 3, 85, 4, 3, 12, 11, 101, 120, 97, 109, 112, 108, 101, 46, 99, 111, 109, 48, 89,
+        48, 19, 6, 7, 42, 134, 72, 206, 61, 2, 1, 6, 8, 42, 134, 72, 206, 61, 3, 1, 7, 3, 66, 0,
+        4, 162, 156, 200, 252, 222, 109, 138, 190, 98, 218, 40, 130, 173, 126, 42, 162, 115, 59,
+        186, 152, 165, 176, 243, 243, 69, 241, 187, 31, 24, 27, 218, 47, 175, 128, 30, 157, 150,
+        60, 208, 253, 113, 50, 159, 79, 51, 97, 92, 237, 225, 84, 245, 212, 189, 37, 237, 55, 192,
+        200, 192, 82, 223, 129, 109, 127, 163, 74, 48, 72, 48, 39, 6, 3, 85, 29, 17, 4, 32, 48,
+        30, 130, 11, 101, 120, 97, 109, 112, 108, 101, 46, 99, 111, 109, 130, 15, 119, 119, 119,
+        46, 101, 120, 97, 109, 112, 108, 101, 46, 99, 111, 109, 48, 29, 6, 3, 85, 29, 14, 4, 22,
+        4, 20, 202, 79, 201, 18, 146, 35, 214, 133, 48, 183, 171, 15, 98, 217, 178, 217, 130, 147,
+        232, 56, 48, 10, 6, 8, 42, 134, 72, 206, 61, 4, 3, 2, 3, 71, 0, 48, 68, 2, 32, 85, 74,
+        195, 241, 74, 26, 21, 56, 55, 11, 10, 143, 219, 130, 193, 34, 91, 60, 185, 195, 103, 236,
+        134, 14, 25, 72, 65, 99, 134, 53, 180, 145, 2, 32, 123, 211, 250, 81, 65, 81, 35, 83, 46,
+        221, 138, 48, 81, 55, 137, 192, 242, 146, 182, 169, 215, 119, 23, 224, 2, 232, 239, 78,
+        34, 148, 89, 182,
+    ];
+
+    // The bare `tbsCertificate` SEQUENCE sliced out of the cert above, as a
+    // precert's `signed_entry` would be.
+    const PRECERT_TBS_DER: &[u8] = &[
+        48, 130, 1, 30, 160, 3, 2, 1, 2, 2, 20, 68, 188, 218, 95, 207, 211, 51, 164, 111, 3, 214,
+        133, 85, 105, 179, 185, 219, 148, 204, 34, 48, 10, 6, 8, 42, 134, 72, 206, 61, 4, 3, 2,
+        48, 22, 49, 20, 48, 18, 6, 3, 85, 4, 3, 12, 11, 101, 120, 97, 109, 112, 108, 101, 46, 99,
+        111, 109, 48, 30, 23, 13, 50, 54, 48, 55, 51, 48, 49, 54, 51, 53, 51, 56, 90, 23, 13, 50,
+        54, 48, 55, 51, 49, 49, 54, 51, 53, 51, 56, 90, 48, 22, 49, 20, 48, 18, 6, 3, 85, 4, 3,
+        12, 11, 101, 120, 97, 109, 112, 108, 101, 46, 99, 111, 109, 48, 89, 48, 19, 6, 7, 42, 134,
+        72, 206, 61, 2, 1, 6, 8, 42, 134, 72, 206, 61, 3, 1, 7, 3, 66, 0, 4, 162, 156, 200, 252,
+        222, 109, 138, 190, 98, 218, 40, 130, 173, 126, 42, 162, 115, 59, 186, 152, 165, 176, 243,
+        243, 69, 241, 187, 31, 24, 27, 218, 47, 175, 128, 30, 157, 150, 60, 208, 253, 113, 50,
+        159, 79, 51, 97, 92, 237, 225, 84, 245, 212, 189, 37, 237, 55, 192, 200, 192, 82, 223,
+        129, 109, 127, 163, 74, 48, 72, 48, 39, 6, 3, 85, 29, 17, 4, 32, 48, 30, 130, 11, 101,
+        120, 97, 109, 112, 108, 101, 46, 99, 111, 109, 130, 15, 119, 119, 119, 46, 101, 120, 97,
+        109, 112, 108, 101, 46, 99, 111, 109, 48, 29, 6, 3, 85, 29, 14, 4, 22, 4, 20, 202, 79,
+        201, 18, 146, 35, 214, 133, 48, 183, 171, 15, 98, 217, 178, 217, 130, 147, 232, 56,
+    ];
+
+    fn merkle_tree_leaf(entry_type: u16, timestamped_entry_rest: &[u8]) -> Vec<u8> {
+        let mut leaf = vec![0u8; 12]; // version + leaf_type + timestamp, all zeroed for the test
+        leaf[10..12].copy_from_slice(&entry_type.to_be_bytes());
+        leaf.extend_from_slice(timestamped_entry_rest);
+        leaf
+    }
+
+    fn opaque24(data: &[u8]) -> Vec<u8> {
+        let len = data.len();
+        let mut out = vec![(len >> 16) as u8, (len >> 8) as u8, len as u8];
+        out.extend_from_slice(data);
+        out
+    }
+
+    #[test]
+    fn extracts_names_from_x509_leaf() {
+        let leaf = merkle_tree_leaf(ENTRY_TYPE_X509, &opaque24(X509_LEAF_CERT_DER));
+
+        let names = extract_names(&leaf).unwrap();
+
+        assert_eq!(names.common_name.as_deref(), Some("example.com"));
+        assert_eq!(
+            names.sans,
+            vec!["example.com".to_string(), "www.example.com".to_string()]
+        );
+    }
+
+    #[test]
+    fn extracts_names_from_precert_leaf() {
+        let mut rest = vec![0u8; 32]; // issuer_key_hash, arbitrary for the test
+        rest.extend_from_slice(&opaque24(PRECERT_TBS_DER));
+        let leaf = merkle_tree_leaf(ENTRY_TYPE_PRECERT, &rest);
+
+        let names = extract_names(&leaf).unwrap();
+
+        assert_eq!(names.common_name.as_deref(), Some("example.com"));
+        assert_eq!(
+            names.sans,
+            vec!["example.com".to_string(), "www.example.com".to_string()]
+        );
+    }
+
+    #[test]
+    fn truncated_input_is_rejected() {
+        assert!(extract_names(&[0u8; 11]).is_err());
+
+        let mut leaf = merkle_tree_leaf(ENTRY_TYPE_X509, &[]);
+        leaf.extend_from_slice(&[0, 0, 10]); // claims a 10-byte cert that isn't there
+        assert!(extract_names(&leaf).is_err());
+
+        let leaf = merkle_tree_leaf(ENTRY_TYPE_PRECERT, &[0u8; 16]); // short issuer_key_hash
+        assert!(extract_names(&leaf).is_err());
+    }
+}
+
+fn names_from_tbs(tbs: TbsCertificate) -> LeafCertNames {
+    let common_name = tbs
+        .subject()
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+        .map(str::to_string);
+
+    let sans = tbs
+        .subject_alternative_name()
+        .ok()
+        .flatten()
+        .map(|(_, ext)| {
+            ext.general_names
+                .iter()
+                .filter_map(|name| match name {
+                    GeneralName::DNSName(dns) => Some(dns.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    LeafCertNames { common_name, sans }
+}
@@ -1,6 +1,9 @@
 use super::error::LogListError;
 use super::types::LogList;
-use reqwest::Client;
+use reqwest::{
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    Client, StatusCode,
+};
 
 const GOOGLE_ALL_LOGLIST_URL: &str = "https://www.gstatic.com/ct/log_list/v3/all_logs_list.json";
 
@@ -9,6 +12,18 @@ pub struct LogListClient {
     url: String,
 }
 
+/// Result of a conditional fetch: either the log list changed (along with
+/// the validators to send next time), or the server confirmed the cached
+/// copy is still current.
+pub enum LogListFetch {
+    Modified {
+        log_list: LogList,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+    NotModified,
+}
+
 impl LogListClient {
     pub fn new(url: String) -> Self {
         Self {
@@ -21,20 +36,121 @@ impl LogListClient {
         Self::new(GOOGLE_ALL_LOGLIST_URL.to_string())
     }
 
-    pub async fn fetch_log_list(&self) -> Result<LogList, LogListError> {
-        self.fetch_from_url(&self.url).await
+    pub async fn fetch_log_list(
+        &self,
+        etag: Option<&str>,
+        last_modified: Option<&str>,
+    ) -> Result<LogListFetch, LogListError> {
+        self.fetch_from_url(&self.url, etag, last_modified).await
     }
 
-    pub async fn fetch_from_url(&self, url: &str) -> Result<LogList, LogListError> {
-        let response = self
-            .client
-            .get(url)
-            .send()
-            .await
+    pub async fn fetch_from_url(
+        &self,
+        url: &str,
+        etag: Option<&str>,
+        last_modified: Option<&str>,
+    ) -> Result<LogListFetch, LogListError> {
+        let mut request = self.client.get(url);
+        if let Some(etag) = etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let response = request.send().await.map_err(LogListError::NetworkError)?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(LogListFetch::NotModified);
+        }
+
+        let response = response
+            .error_for_status()
             .map_err(LogListError::NetworkError)?;
 
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let last_modified = response
+            .headers()
+            .get(LAST_MODIFIED)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
         let text = response.text().await.map_err(LogListError::NetworkError)?;
         println!("REST call to {}", url);
-        serde_json::from_str(&text).map_err(|e| LogListError::ParseError(e.to_string()))
+        let log_list =
+            serde_json::from_str(&text).map_err(|e| LogListError::ParseError(e.to_string()))?;
+
+        Ok(LogListFetch::Modified {
+            log_list,
+            etag,
+            last_modified,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    /// Spawns a one-shot local HTTP server that replies with a fixed,
+    /// raw response to the first connection it receives, so tests can
+    /// exercise `fetch_from_url`'s header handling without a live network
+    /// call.
+    fn spawn_one_shot_server(response: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        format!("http://{addr}")
+    }
+
+    #[tokio::test]
+    async fn not_modified_response_yields_not_modified_variant() {
+        let url = spawn_one_shot_server("HTTP/1.1 304 Not Modified\r\n\r\n");
+        let client = LogListClient::new(url.clone());
+
+        let result = client
+            .fetch_from_url(&url, Some("\"some-etag\""), None)
+            .await
+            .unwrap();
+
+        assert!(matches!(result, LogListFetch::NotModified));
+    }
+
+    #[tokio::test]
+    async fn modified_response_returns_parsed_log_list_and_validators() {
+        let body = r#"{"is_all_logs":true,"version":"1","log_list_timestamp":"2024-01-01T00:00:00Z","operators":[]}"#;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nETag: \"abc\"\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let url = spawn_one_shot_server(Box::leak(response.into_boxed_str()));
+        let client = LogListClient::new(url.clone());
+
+        let result = client.fetch_from_url(&url, None, None).await.unwrap();
+
+        match result {
+            LogListFetch::Modified {
+                log_list, etag, ..
+            } => {
+                assert_eq!(log_list.operators.len(), 0);
+                assert_eq!(etag.as_deref(), Some("\"abc\""));
+            }
+            LogListFetch::NotModified => panic!("expected a Modified result"),
+        }
     }
 }
@@ -0,0 +1,7 @@
+pub mod client;
+pub mod error;
+pub mod service;
+pub mod tiled_client;
+pub mod types;
+
+pub use types::{Log, LogList, TiledLog};
@@ -1,10 +1,18 @@
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex},
-    time::{Duration, SystemTime},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use super::{client::LogListClient, error::LogListError, types::Log};
+use log::{debug, warn};
+
+use crate::monitor_store::{InMemoryMonitorStore, LogListSnapshot, MonitorStore, MonitorStoreExt};
+
+use super::{
+    client::{LogListClient, LogListFetch},
+    error::LogListError,
+    types::{Log, LogList, TiledLog},
+};
 
 const DEFAULT_CACHE_DURATION: Duration = Duration::from_secs(60 * 60 * 24); // 1 days
 
@@ -12,7 +20,11 @@ struct CachedLogs {
     logs: Vec<Log>,
     logs_by_id: HashMap<String, usize>,
     logs_by_operator: HashMap<String, Vec<usize>>,
+    tiled_logs_by_operator: HashMap<String, Vec<TiledLog>>,
+    etag: Option<String>,
+    last_modified: Option<String>,
     last_updated: SystemTime,
+    loaded_from_store: bool,
 }
 
 impl Default for CachedLogs {
@@ -21,8 +33,53 @@ impl Default for CachedLogs {
             logs: Vec::new(),
             logs_by_id: HashMap::new(),
             logs_by_operator: HashMap::new(),
+            tiled_logs_by_operator: HashMap::new(),
+            etag: None,
+            last_modified: None,
             last_updated: SystemTime::UNIX_EPOCH,
+            loaded_from_store: false,
+        }
+    }
+}
+
+impl CachedLogs {
+    fn apply(&mut self, log_list: &LogList, fetched_at: SystemTime) {
+        let mut logs = Vec::new();
+        let mut logs_by_operator = HashMap::new();
+        let mut logs_by_id = HashMap::new();
+        let mut tiled_logs_by_operator = HashMap::new();
+
+        for operator in &log_list.operators {
+            let mut operator_indices = Vec::new();
+
+            for log in &operator.logs {
+                // Unusable logs are not included here
+                if !log.is_usable() {
+                    continue;
+                }
+
+                let index = logs.len();
+                logs.push(log.clone());
+                logs_by_id.insert(log.log_id.clone(), index);
+                operator_indices.push(index);
+            }
+
+            logs_by_operator.insert(operator.name.clone(), operator_indices);
+
+            let usable_tiled_logs: Vec<TiledLog> = operator
+                .tiled_logs
+                .iter()
+                .filter(|log| log.is_usable())
+                .cloned()
+                .collect();
+            tiled_logs_by_operator.insert(operator.name.clone(), usable_tiled_logs);
         }
+
+        self.logs = logs;
+        self.logs_by_id = logs_by_id;
+        self.logs_by_operator = logs_by_operator;
+        self.tiled_logs_by_operator = tiled_logs_by_operator;
+        self.last_updated = fetched_at;
     }
 }
 
@@ -30,14 +87,27 @@ pub struct CachingLogListService {
     client: LogListClient,
     cache: Arc<Mutex<CachedLogs>>,
     cache_duration: Duration,
+    store: Arc<dyn MonitorStore>,
 }
 
 impl CachingLogListService {
-    pub fn new(cache_duration: Duration) -> Self {
+    pub fn new(cache_duration: Duration, store: Arc<dyn MonitorStore>) -> Self {
         Self {
             client: LogListClient::new_google(),
             cache: Arc::new(Mutex::new(CachedLogs::default())),
             cache_duration,
+            store,
+        }
+    }
+
+    /// Like [`Self::new`], but fetching the log list from a custom URL
+    /// instead of Google's `all_logs_list.json`.
+    pub fn with_url(cache_duration: Duration, url: String, store: Arc<dyn MonitorStore>) -> Self {
+        Self {
+            client: LogListClient::new(url),
+            cache: Arc::new(Mutex::new(CachedLogs::default())),
+            cache_duration,
+            store,
         }
     }
 
@@ -60,7 +130,74 @@ impl CachingLogListService {
             .unwrap_or_default())
     }
 
+    pub async fn get_tiled_by_operator(
+        &self,
+        operator: &str,
+    ) -> Result<Vec<TiledLog>, LogListError> {
+        self.check_and_refresh_cache().await?;
+        let cache = self.cache.lock().unwrap();
+        Ok(cache
+            .tiled_logs_by_operator
+            .get(operator)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// How long it's been since the cached log list was last confirmed
+    /// current, so callers can warn when they're operating on a stale list.
+    pub fn staleness(&self) -> Duration {
+        let cache = self.cache.lock().unwrap();
+        SystemTime::now()
+            .duration_since(cache.last_updated)
+            .unwrap_or_default()
+    }
+
+    /// Seeds the in-memory cache from the persisted snapshot, if any, the
+    /// first time the cache is touched. Runs at most once per instance.
+    async fn load_from_store(&self) {
+        {
+            let cache = self.cache.lock().unwrap();
+            if cache.loaded_from_store {
+                return;
+            }
+        }
+
+        match self.store.load_log_list_snapshot().await {
+            Ok(Some(snapshot)) => {
+                debug!("Seeding log list cache from persisted snapshot");
+                let fetched_at =
+                    UNIX_EPOCH + Duration::from_secs(snapshot.fetched_at);
+                let mut cache = self.cache.lock().unwrap();
+                cache.apply(&snapshot.log_list, fetched_at);
+                cache.etag = snapshot.etag;
+                cache.last_modified = snapshot.last_modified;
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Error loading persisted log list snapshot: {}", e),
+        }
+
+        self.cache.lock().unwrap().loaded_from_store = true;
+    }
+
+    async fn persist(&self, log_list: &LogList, fetched_at: SystemTime, etag: &Option<String>, last_modified: &Option<String>) {
+        let snapshot = LogListSnapshot {
+            log_list: log_list.clone(),
+            fetched_at: fetched_at
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            etag: etag.clone(),
+            last_modified: last_modified.clone(),
+        };
+
+        if let Err(e) = self.store.save_log_list_snapshot(&snapshot).await {
+            warn!("Error persisting log list snapshot: {}", e);
+        }
+    }
+
     async fn check_and_refresh_cache(&self) -> Result<(), LogListError> {
+        self.load_from_store().await;
+
         let now = SystemTime::now();
         {
             let cache = self.cache.lock().unwrap();
@@ -68,47 +205,198 @@ impl CachingLogListService {
                 .duration_since(cache.last_updated)
                 .map(|duration| duration < self.cache_duration)
                 .unwrap_or(false);
-            drop(cache);
 
             if fresh {
                 return Ok(());
             }
         }
 
-        let new_log_list = self.client.fetch_log_list().await?;
-        let mut logs = Vec::new();
-        let mut logs_by_operator = HashMap::new();
-        let mut logs_by_id = HashMap::new();
-
-        for operator in &new_log_list.operators {
-            let mut operator_indices = Vec::new();
+        let (etag, last_modified) = {
+            let cache = self.cache.lock().unwrap();
+            (cache.etag.clone(), cache.last_modified.clone())
+        };
 
-            for log in &operator.logs {
-                // Unusable logs are not included here
-                if !log.is_usable() {
-                    continue;
+        match self
+            .client
+            .fetch_log_list(etag.as_deref(), last_modified.as_deref())
+            .await
+        {
+            Ok(LogListFetch::NotModified) => {
+                debug!("Log list not modified since last fetch");
+                {
+                    let mut cache = self.cache.lock().unwrap();
+                    cache.last_updated = now;
+                }
+                // The body itself hasn't changed; just refresh `fetched_at`
+                // so a restart inside the window doesn't re-fetch either.
+                if let Ok(Some(mut snapshot)) = self.store.load_log_list_snapshot().await {
+                    snapshot.fetched_at = now
+                        .duration_since(UNIX_EPOCH)
+                        .map(|d| d.as_secs())
+                        .unwrap_or(0);
+                    if let Err(e) = self.store.save_log_list_snapshot(&snapshot).await {
+                        warn!("Error persisting log list snapshot: {}", e);
+                    }
+                }
+                Ok(())
+            }
+            Ok(LogListFetch::Modified {
+                log_list,
+                etag,
+                last_modified,
+            }) => {
+                {
+                    let mut cache = self.cache.lock().unwrap();
+                    cache.apply(&log_list, now);
+                    cache.etag = etag.clone();
+                    cache.last_modified = last_modified.clone();
+                }
+                self.persist(&log_list, now, &etag, &last_modified).await;
+                Ok(())
+            }
+            Err(e) => {
+                let has_data = !self.cache.lock().unwrap().logs.is_empty();
+                if has_data {
+                    warn!(
+                        "Error refreshing log list, serving stale cached copy ({} old): {}",
+                        humantime_age(self.staleness()),
+                        e
+                    );
+                    Ok(())
+                } else {
+                    Err(e)
                 }
-
-                let index = logs.len();
-                logs.push(log.clone());
-                logs_by_id.insert(log.log_id.clone(), index);
-                operator_indices.push(index);
             }
-
-            logs_by_operator.insert(operator.name.clone(), operator_indices);
         }
-
-        let mut cache = self.cache.lock().unwrap();
-        cache.logs = logs;
-        cache.logs_by_id = logs_by_id;
-        cache.logs_by_operator = logs_by_operator;
-        cache.last_updated = now;
-        Ok(())
     }
 }
 
+fn humantime_age(age: Duration) -> String {
+    format!("{}s", age.as_secs())
+}
+
 impl Default for CachingLogListService {
     fn default() -> Self {
-        Self::new(DEFAULT_CACHE_DURATION)
+        Self::new(DEFAULT_CACHE_DURATION, Arc::new(InMemoryMonitorStore::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log_list::types::{LogState, Operator};
+    use chrono::Utc;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    fn sample_log_list(operator_name: &str) -> LogList {
+        LogList {
+            is_all_logs: true,
+            version: "1".to_string(),
+            log_list_timestamp: Utc::now(),
+            operators: vec![Operator {
+                name: operator_name.to_string(),
+                email: Vec::new(),
+                logs: vec![Log {
+                    description: "Test Log".to_string(),
+                    log_id: "test-log".to_string(),
+                    key: vec![0u8; 32],
+                    url: "https://example.com/ct/".to_string(),
+                    mmd: 86400,
+                    state: Some(LogState::Usable {
+                        timestamp: Utc::now(),
+                    }),
+                    temporal_interval: None,
+                    log_type: None,
+                }],
+                tiled_logs: Vec::new(),
+            }],
+        }
+    }
+
+    /// Spawns a one-shot local HTTP server that replies with a fixed, raw
+    /// response to the first connection it receives.
+    fn spawn_one_shot_server(response: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        format!("http://{addr}")
+    }
+
+    #[test]
+    fn apply_indexes_only_usable_logs() {
+        let mut usable = sample_log_list("Test Operator");
+        let mut unusable_log = usable.operators[0].logs[0].clone();
+        unusable_log.log_id = "retired-log".to_string();
+        unusable_log.state = Some(LogState::Retired {
+            timestamp: Utc::now(),
+        });
+        usable.operators[0].logs.push(unusable_log);
+
+        let mut cache = CachedLogs::default();
+        cache.apply(&usable, SystemTime::now());
+
+        assert_eq!(cache.logs.len(), 1);
+        assert!(cache.logs_by_id.contains_key("test-log"));
+        assert!(!cache.logs_by_id.contains_key("retired-log"));
+        assert_eq!(cache.logs_by_operator["Test Operator"].len(), 1);
+    }
+
+    #[tokio::test]
+    async fn network_error_falls_back_to_persisted_log_list() {
+        let store: Arc<dyn MonitorStore> = Arc::new(InMemoryMonitorStore::new());
+        let snapshot = LogListSnapshot {
+            log_list: sample_log_list("Test Operator"),
+            fetched_at: 0,
+            etag: None,
+            last_modified: None,
+        };
+        store.save_log_list_snapshot(&snapshot).await.unwrap();
+
+        // Port 0 connections are refused immediately, simulating a network
+        // error without relying on external connectivity.
+        let service = CachingLogListService::with_url(
+            Duration::from_secs(0),
+            "http://127.0.0.1:0".to_string(),
+            store,
+        );
+
+        let logs = service
+            .get_all_by_operator("Test Operator")
+            .await
+            .expect("should fall back to the persisted log list");
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].log_id, "test-log");
+    }
+
+    #[tokio::test]
+    async fn not_modified_response_keeps_serving_cached_logs() {
+        let store: Arc<dyn MonitorStore> = Arc::new(InMemoryMonitorStore::new());
+        let snapshot = LogListSnapshot {
+            log_list: sample_log_list("Test Operator"),
+            fetched_at: 0,
+            etag: Some("\"cached-etag\"".to_string()),
+            last_modified: None,
+        };
+        store.save_log_list_snapshot(&snapshot).await.unwrap();
+
+        let url = spawn_one_shot_server("HTTP/1.1 304 Not Modified\r\n\r\n");
+        let service =
+            CachingLogListService::with_url(Duration::from_secs(0), url, store);
+
+        let logs = service
+            .get_all_by_operator("Test Operator")
+            .await
+            .expect("304 should keep serving the cached copy");
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].log_id, "test-log");
     }
 }
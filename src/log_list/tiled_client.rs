@@ -0,0 +1,171 @@
+//! Client for the static-CT ("tiled log") monitoring API: fetches a log's
+//! `checkpoint` (a signed note, see <https://c2sp.org/static-ct-api>) and
+//! verifies it against the log's key.
+
+use anyhow::{anyhow, bail, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use prism_keys::{CryptoAlgorithm::Secp256r1, Signature, VerifyingKey};
+use reqwest::Client;
+
+/// A verified tree head read from a tiled log's checkpoint.
+#[derive(Debug, Clone)]
+pub struct TiledTreeHead {
+    pub origin: String,
+    pub tree_size: u64,
+    pub root_hash: [u8; 32],
+    signature: Vec<u8>,
+}
+
+impl TiledTreeHead {
+    /// The raw signature bytes as published in the checkpoint (a 4-byte key
+    /// identifier followed by a DER-encoded signature).
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// The exact bytes the log's signature was computed over.
+    pub fn signed_body(&self) -> Vec<u8> {
+        signed_body(self)
+    }
+}
+
+pub struct TiledLogClient {
+    client: Client,
+}
+
+impl Default for TiledLogClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TiledLogClient {
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+        }
+    }
+
+    /// Fetches and parses the `checkpoint` file published at a tiled log's
+    /// `monitoring_url`. Callers should verify the signature with
+    /// [`verify_checkpoint`] before trusting the contents.
+    pub async fn fetch_checkpoint(&self, monitoring_url: &str) -> Result<TiledTreeHead> {
+        let url = join_url(monitoring_url, "checkpoint");
+        let text = self
+            .client
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        parse_checkpoint(&text)
+    }
+}
+
+fn join_url(base: &str, path: &str) -> String {
+    if base.ends_with('/') {
+        format!("{base}{path}")
+    } else {
+        format!("{base}/{path}")
+    }
+}
+
+/// Parses a checkpoint (signed note) body:
+///
+/// ```text
+/// <origin>
+/// <decimal tree size>
+/// <base64 root hash>
+///
+/// — <log name> <base64 signature>
+/// ```
+fn parse_checkpoint(text: &str) -> Result<TiledTreeHead> {
+    let (body, signature_block) = text
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow!("malformed checkpoint: missing signature block"))?;
+
+    let mut lines = body.lines();
+    let origin = lines
+        .next()
+        .ok_or_else(|| anyhow!("malformed checkpoint: missing origin line"))?
+        .to_string();
+    let tree_size: u64 = lines
+        .next()
+        .ok_or_else(|| anyhow!("malformed checkpoint: missing tree size line"))?
+        .parse()
+        .map_err(|e| anyhow!("malformed checkpoint: invalid tree size: {}", e))?;
+    let root_hash_b64 = lines
+        .next()
+        .ok_or_else(|| anyhow!("malformed checkpoint: missing root hash line"))?;
+    let root_hash_bytes = BASE64.decode(root_hash_b64)?;
+    let root_hash: [u8; 32] = root_hash_bytes
+        .try_into()
+        .map_err(|_| anyhow!("checkpoint root hash is not 32 bytes"))?;
+
+    let signature_line = signature_block
+        .lines()
+        .find(|line| line.starts_with("\u{2014} "))
+        .ok_or_else(|| anyhow!("malformed checkpoint: missing note signature line"))?;
+
+    let signature_b64 = signature_line
+        .trim_start_matches("\u{2014} ")
+        .rsplit(' ')
+        .next()
+        .ok_or_else(|| anyhow!("malformed checkpoint: empty signature line"))?;
+    let signature = BASE64.decode(signature_b64)?;
+
+    Ok(TiledTreeHead {
+        origin,
+        tree_size,
+        root_hash,
+        signature,
+    })
+}
+
+/// Verifies a tiled log's checkpoint signature against its public key,
+/// mirroring how `watch_log` verifies an RFC 6962 STH signature: the first
+/// four bytes of the signature are a key identifier, and the remainder is
+/// the DER-encoded signature over the checkpoint body.
+pub fn verify_checkpoint(checkpoint: &TiledTreeHead, log_vk: &VerifyingKey) -> Result<()> {
+    if checkpoint.signature.len() <= 4 {
+        bail!("checkpoint signature too short");
+    }
+
+    let signature = Signature::from_algorithm_and_der(Secp256r1, &checkpoint.signature[4..])?;
+    let body = signed_body(checkpoint);
+
+    log_vk
+        .verify_signature(&body, &signature)
+        .map_err(|e| anyhow!("checkpoint signature verification failed: {}", e))
+}
+
+fn signed_body(checkpoint: &TiledTreeHead) -> Vec<u8> {
+    format!(
+        "{}\n{}\n{}\n",
+        checkpoint.origin,
+        checkpoint.tree_size,
+        BASE64.encode(checkpoint.root_hash)
+    )
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_well_formed_checkpoint() {
+        let checkpoint = "example.com/ct\n42\nYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWE=\n\n\u{2014} example.com/ct AAAAQUJD\n";
+        let parsed = parse_checkpoint(checkpoint).unwrap();
+        assert_eq!(parsed.origin, "example.com/ct");
+        assert_eq!(parsed.tree_size, 42);
+        assert_eq!(parsed.signature, BASE64.decode("AAAAQUJD").unwrap());
+    }
+
+    #[test]
+    fn rejects_checkpoint_without_signature_block() {
+        let checkpoint = "example.com/ct\n42\nYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWFhYWE=\n";
+        assert!(parse_checkpoint(checkpoint).is_err());
+    }
+}
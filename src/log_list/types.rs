@@ -60,6 +60,12 @@ pub struct TiledLog {
     pub log_type: Option<String>,
 }
 
+impl TiledLog {
+    pub fn is_usable(&self) -> bool {
+        matches!(self.state, Some(LogState::Usable { .. }))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TemporalInterval {
     pub start_inclusive: DateTime<Utc>,
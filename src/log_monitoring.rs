@@ -2,7 +2,7 @@ use std::{sync::Arc, time::Duration};
 
 use ctclient::{CTClient, SthResult};
 use keystore_rs::{KeyChain, KeyStore as _};
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use prism_common::{
     account::Account,
     digest::Digest,
@@ -10,41 +10,123 @@ use prism_common::{
 };
 use prism_keys::{CryptoAlgorithm::Secp256r1, Signature, SigningKey, VerifyingKey};
 use prism_prover::{prover::AccountResponse::Found, Prover};
+use tokio::sync::Mutex as AsyncMutex;
 
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 
 use anyhow::{anyhow, bail, Result};
 
 use crate::{
-    log_list::{service::CachingLogListService, Log},
-    CT_SERVICE_KEY_ID,
+    config::ServiceConfig,
+    consistency::{
+        fetch_consistency_proof, fetch_entries, fetch_latest_tree_size, verify_consistency_proof,
+    },
+    leaf_entry::extract_names,
+    log_list::{
+        service::CachingLogListService,
+        tiled_client::{verify_checkpoint, TiledLogClient},
+        Log, TiledLog,
+    },
+    monitor_store::{LogCheckpoint, MonitorStore, MonitorStoreExt},
+    watchlist::{Watchlist, WatchlistEvent},
 };
 
+pub static WATCHLIST_ACCOUNT_ID: &str = "ct_watchlist";
+
 pub async fn monitor_operators(
-    operators: Vec<String>,
-    interval: Duration,
+    config: Arc<ServiceConfig>,
     signing_key: SigningKey,
     prover: Arc<Prover>,
+    store: Arc<dyn MonitorStore>,
+    watchlist: Arc<Watchlist>,
 ) -> Result<()> {
-    let log_list = CachingLogListService::default();
+    let log_list = match &config.log_list_url {
+        Some(url) => CachingLogListService::with_url(
+            config.log_list_cache_duration(),
+            url.clone(),
+            store.clone(),
+        ),
+        None => CachingLogListService::new(config.log_list_cache_duration(), store.clone()),
+    };
 
-    register_ct_service(prover.clone()).await?;
+    register_ct_service(&config.keystore_key_id, prover.clone()).await?;
 
-    for operator in &operators {
+    let watchlist_account = Arc::new(AsyncMutex::new(
+        create_watchlist_account(&config.keystore_key_id, prover.clone()).await?,
+    ));
+
+    let interval = config.poll_interval();
+
+    for operator in &config.operators {
         let Ok(logs) = log_list.get_all_by_operator(operator).await else {
             bail!("Error fetching logs for {}", operator);
         };
 
+        let staleness = log_list.staleness();
+        if staleness > config.log_list_cache_duration() * 2 {
+            warn!(
+                "Log list has not refreshed in {}s, operating on a stale copy",
+                staleness.as_secs()
+            );
+        }
+
         debug!("Found {} logs for operator {}", logs.len(), operator);
 
         for log in logs {
             info!("Spawning monitoring task for {}", log.description);
 
+            let task_key_id = config.keystore_key_id.clone();
             let task_signing_key = signing_key.clone();
             let task_prover = prover.clone();
+            let task_store = store.clone();
+            let task_watchlist = watchlist.clone();
+            let task_watchlist_account = watchlist_account.clone();
+
+            let future = async move {
+                monitor_log(
+                    task_key_id,
+                    log,
+                    task_prover,
+                    task_signing_key,
+                    interval,
+                    task_store,
+                    task_watchlist,
+                    task_watchlist_account,
+                )
+                .await
+            };
+            tokio::task::spawn(future);
+        }
+
+        let Ok(tiled_logs) = log_list.get_tiled_by_operator(operator).await else {
+            bail!("Error fetching tiled logs for {}", operator);
+        };
 
-            let future =
-                async move { monitor_log(log, task_prover, task_signing_key, interval).await };
+        debug!(
+            "Found {} tiled logs for operator {}",
+            tiled_logs.len(),
+            operator
+        );
+
+        for tiled_log in tiled_logs {
+            info!("Spawning tiled monitoring task for {}", tiled_log.description);
+
+            let task_key_id = config.keystore_key_id.clone();
+            let task_signing_key = signing_key.clone();
+            let task_prover = prover.clone();
+            let task_store = store.clone();
+
+            let future = async move {
+                monitor_tiled_log(
+                    task_key_id,
+                    tiled_log,
+                    task_prover,
+                    task_signing_key,
+                    interval,
+                    task_store,
+                )
+                .await
+            };
             tokio::task::spawn(future);
         }
     }
@@ -52,27 +134,27 @@ pub async fn monitor_operators(
     Ok(())
 }
 
-async fn register_ct_service(prover: Arc<Prover>) -> Result<()> {
-    if let Found(_, _) = prover.get_account(CT_SERVICE_KEY_ID).await? {
+async fn register_ct_service(key_id: &str, prover: Arc<Prover>) -> Result<()> {
+    if let Found(_, _) = prover.get_account(key_id).await? {
         debug!("Service already registered.");
         return Ok(());
     };
 
     let keystore_sk = KeyChain
-        .get_signing_key(CT_SERVICE_KEY_ID)
+        .get_signing_key(key_id)
         .map_err(|e| anyhow!("Error getting key from store: {}", e))?;
 
     let sk = SigningKey::Ed25519(Box::new(keystore_sk));
     let vk: VerifyingKey = sk.verifying_key();
 
     let register_op = Operation::RegisterService {
-        id: CT_SERVICE_KEY_ID.to_string(),
+        id: key_id.to_string(),
         creation_gate: ServiceChallenge::Signed(vk.clone()),
         key: vk,
     };
 
     let register_tx =
-        Account::default().prepare_transaction(CT_SERVICE_KEY_ID.to_string(), register_op, &sk)?;
+        Account::default().prepare_transaction(key_id.to_string(), register_op, &sk)?;
 
     debug!("Submitting transaction to register CT service");
     prover
@@ -84,16 +166,43 @@ async fn register_ct_service(prover: Arc<Prover>) -> Result<()> {
 }
 
 async fn monitor_log(
+    key_id: String,
     log: Log,
     prover: Arc<Prover>,
     signing_key: SigningKey,
     interval: Duration,
+    store: Arc<dyn MonitorStore>,
+    watchlist: Arc<Watchlist>,
+    watchlist_account: Arc<AsyncMutex<Account>>,
 ) -> Result<()> {
-    let mut account = create_log_account(log.clone(), prover.clone()).await?;
-    watch_log(log, prover.clone(), signing_key, &mut account, interval).await
+    let mut account = create_log_account(&key_id, log.clone(), prover.clone()).await?;
+
+    let checkpoint = store.load_checkpoint(&log.log_id).await.unwrap_or_else(|e| {
+        error!(
+            "Error loading checkpoint for {}, starting from scratch: {}",
+            log.description, e
+        );
+        None
+    });
+    if checkpoint.is_some() {
+        debug!("Resuming {} from persisted checkpoint", log.description);
+    }
+
+    watch_log(
+        log,
+        prover.clone(),
+        signing_key,
+        &mut account,
+        interval,
+        store,
+        checkpoint,
+        watchlist,
+        watchlist_account,
+    )
+    .await
 }
 
-async fn create_log_account(log: Log, prover: Arc<Prover>) -> Result<Account> {
+async fn create_log_account(key_id: &str, log: Log, prover: Arc<Prover>) -> Result<Account> {
     if let Found(account, _) = prover.get_account(&log.log_id).await? {
         debug!(
             "Account {} ({}) exists already",
@@ -103,23 +212,19 @@ async fn create_log_account(log: Log, prover: Arc<Prover>) -> Result<Account> {
     };
 
     let keystore_sk = KeyChain
-        .get_signing_key(CT_SERVICE_KEY_ID)
+        .get_signing_key(key_id)
         .map_err(|e| anyhow!("Error getting key from store: {}", e))?;
 
     let sk = SigningKey::Ed25519(Box::new(keystore_sk));
     let vk: VerifyingKey = sk.verifying_key();
 
     // Sign account creation credentials with CT service's signing key
-    let hash = Digest::hash_items(&[
-        log.log_id.as_bytes(),
-        CT_SERVICE_KEY_ID.as_bytes(),
-        &vk.to_bytes(),
-    ]);
+    let hash = Digest::hash_items(&[log.log_id.as_bytes(), key_id.as_bytes(), &vk.to_bytes()]);
     let signature = sk.sign(&hash.to_bytes());
 
     let create_acc_op = Operation::CreateAccount {
         id: log.log_id.clone(),
-        service_id: CT_SERVICE_KEY_ID.to_string(),
+        service_id: key_id.to_string(),
         challenge: ServiceChallengeInput::Signed(signature),
         key: vk,
     };
@@ -140,15 +245,88 @@ async fn create_log_account(log: Log, prover: Arc<Prover>) -> Result<Account> {
     Ok(account)
 }
 
+/// Creates the account that watchlist match events are recorded under. It's
+/// gated and signed by the CT service's own key, since these events are
+/// produced internally rather than attested by an external log.
+async fn create_watchlist_account(key_id: &str, prover: Arc<Prover>) -> Result<Account> {
+    if let Found(account, _) = prover.get_account(WATCHLIST_ACCOUNT_ID).await? {
+        debug!("Watchlist account exists already");
+        return Ok(*account);
+    };
+
+    let keystore_sk = KeyChain
+        .get_signing_key(key_id)
+        .map_err(|e| anyhow!("Error getting key from store: {}", e))?;
+
+    let sk = SigningKey::Ed25519(Box::new(keystore_sk));
+    let vk: VerifyingKey = sk.verifying_key();
+
+    let hash = Digest::hash_items(&[
+        WATCHLIST_ACCOUNT_ID.as_bytes(),
+        key_id.as_bytes(),
+        &vk.to_bytes(),
+    ]);
+    let signature = sk.sign(&hash.to_bytes());
+
+    let create_acc_op = Operation::CreateAccount {
+        id: WATCHLIST_ACCOUNT_ID.to_string(),
+        service_id: key_id.to_string(),
+        challenge: ServiceChallengeInput::Signed(signature),
+        key: vk,
+    };
+
+    let mut account = Account::default();
+    let create_acc_tx =
+        account.prepare_transaction(WATCHLIST_ACCOUNT_ID.to_string(), create_acc_op, &sk)?;
+
+    debug!("Submitting transaction to create watchlist account");
+    prover
+        .clone()
+        .validate_and_queue_update(create_acc_tx.clone())
+        .await?;
+
+    account.process_transaction(&create_acc_tx)?;
+    Ok(account)
+}
+
 async fn watch_log(
     log: Log,
     prover: Arc<Prover>,
     service_sk: SigningKey,
     account: &mut Account,
     interval: Duration,
+    store: Arc<dyn MonitorStore>,
+    checkpoint: Option<LogCheckpoint>,
+    watchlist: Arc<Watchlist>,
+    watchlist_account: Arc<AsyncMutex<Account>>,
 ) -> Result<()> {
     let log_vk = VerifyingKey::from_algorithm_and_der(Secp256r1, &log.key)?;
 
+    let mut last_tree_head = checkpoint.map(|c| c.root_hash).unwrap_or([0u8; 32]);
+    let mut last_tree_size = checkpoint.map(|c| c.tree_size).unwrap_or(0);
+
+    // `CTClient::new_from_latest_th` always starts tracking from the log's
+    // current tip, so anything appended between the persisted checkpoint
+    // and that tip has to be backfilled by hand before it starts, or it
+    // would never reach the watchlist callback.
+    if !watchlist.is_empty() && last_tree_size > 0 {
+        if let Err(e) = backfill_watchlist_gap(
+            &log,
+            last_tree_size,
+            &watchlist,
+            &watchlist_account,
+            &service_sk,
+            &prover,
+        )
+        .await
+        {
+            warn!(
+                "Error backfilling missed entries for {}: {}",
+                log.description, e
+            );
+        }
+    }
+
     let mut client = CTClient::new_from_latest_th(&log.url, &log.key).map_err(|e| {
         anyhow!(
             "Error initializing client for log {}: {}",
@@ -157,14 +335,57 @@ async fn watch_log(
         )
     })?;
 
-    let mut last_tree_head = [0u8; 32];
     loop {
-        let update_result = client.light_update();
+        let mut new_entries: Vec<(u64, Vec<u8>)> = Vec::new();
+        let update_result = {
+            let mut on_new_entry = |index: u64, leaf_input: &[u8]| {
+                new_entries.push((index, leaf_input.to_vec()));
+            };
+            client.update(Some(&mut on_new_entry))
+        };
 
         match update_result {
             SthResult::Ok(head) => {
                 if !head.root_hash.eq(&last_tree_head) {
+                    let new_tree_size = head.tree_size as u64;
+
+                    let consistent = if last_tree_size == 0 {
+                        true
+                    } else {
+                        match fetch_consistency_proof(&log.url, last_tree_size, new_tree_size).await
+                        {
+                            Ok(proof) => verify_consistency_proof(
+                                last_tree_size,
+                                new_tree_size,
+                                &proof,
+                                last_tree_head,
+                                head.root_hash,
+                            ),
+                            Err(e) => {
+                                error!(
+                                    "Error fetching consistency proof for {}: {}",
+                                    log.description, e
+                                );
+                                false
+                            }
+                        }
+                    };
+
+                    if !consistent {
+                        error!(
+                            "Consistency proof verification failed for {}: old STH (size {}, root {}) -> new STH (size {}, root {})",
+                            log.description,
+                            last_tree_size,
+                            BASE64.encode(last_tree_head),
+                            new_tree_size,
+                            BASE64.encode(head.root_hash)
+                        );
+                        tokio::time::sleep(interval).await;
+                        continue;
+                    }
+
                     last_tree_head = head.root_hash;
+                    last_tree_size = new_tree_size;
                     let relevant_head_signature_slice = &head.signature[4..];
                     let signature = Signature::from_algorithm_and_der(
                         Secp256r1,
@@ -202,25 +423,363 @@ async fn watch_log(
                         };
                     }
                     account.process_transaction(&update_tx)?;
+
+                    let checkpoint = LogCheckpoint {
+                        root_hash: head.root_hash,
+                        tree_size: head.tree_size as u64,
+                        timestamp: head.timestamp as u64,
+                    };
+                    if let Err(e) = store.save_checkpoint(&log.log_id, &checkpoint).await {
+                        error!("Error persisting checkpoint for {}: {}", log.description, e);
+                    }
+
+                    if !watchlist.is_empty() {
+                        for (leaf_index, leaf_input) in &new_entries {
+                            if let Err(e) = record_watchlist_matches(
+                                &log,
+                                *leaf_index,
+                                leaf_input,
+                                &watchlist,
+                                &watchlist_account,
+                                &service_sk,
+                                &prover,
+                            )
+                            .await
+                            {
+                                warn!(
+                                    "Error matching leaf {} of {} against watchlist: {}",
+                                    leaf_index, log.description, e
+                                );
+                            }
+                        }
+                    }
                 }
             }
             SthResult::Err(e) => {
                 error!("Error in log {}: {}", log.description, e);
             }
             SthResult::ErrWithSth(e, head) => {
-                error!("Error with sth in log {}: {}", log.description, e);
+                // `head` here is unverified (ctclient couldn't complete the
+                // update that would have confirmed it), so it must not feed
+                // `last_tree_head`/`last_tree_size`: the consistency check
+                // above assumes that pair is always a verified root, and
+                // trusting this one would jam every future round against a
+                // root that was never actually checked.
+                error!(
+                    "Error with unverified sth in log {}: {} (root {})",
+                    log.description,
+                    e,
+                    BASE64.encode(head.root_hash)
+                );
+            }
+        }
 
-                if !head.root_hash.eq(&last_tree_head) {
+        tokio::time::sleep(interval).await;
+    }
+}
+
+async fn monitor_tiled_log(
+    key_id: String,
+    tiled_log: TiledLog,
+    prover: Arc<Prover>,
+    signing_key: SigningKey,
+    interval: Duration,
+    store: Arc<dyn MonitorStore>,
+) -> Result<()> {
+    let mut account = create_tiled_log_account(&key_id, &tiled_log, prover.clone()).await?;
+
+    let checkpoint = store
+        .load_checkpoint(&tiled_log.log_id)
+        .await
+        .unwrap_or_else(|e| {
+            error!(
+                "Error loading checkpoint for {}, starting from scratch: {}",
+                tiled_log.description, e
+            );
+            None
+        });
+    if checkpoint.is_some() {
+        debug!("Resuming {} from persisted checkpoint", tiled_log.description);
+    }
+
+    watch_tiled_log(
+        tiled_log,
+        prover,
+        signing_key,
+        &mut account,
+        interval,
+        store,
+        checkpoint,
+    )
+    .await
+}
+
+async fn create_tiled_log_account(
+    key_id: &str,
+    tiled_log: &TiledLog,
+    prover: Arc<Prover>,
+) -> Result<Account> {
+    if let Found(account, _) = prover.get_account(&tiled_log.log_id).await? {
+        debug!(
+            "Account {} ({}) exists already",
+            tiled_log.log_id, tiled_log.description
+        );
+        return Ok(*account);
+    };
+
+    let keystore_sk = KeyChain
+        .get_signing_key(key_id)
+        .map_err(|e| anyhow!("Error getting key from store: {}", e))?;
+
+    let sk = SigningKey::Ed25519(Box::new(keystore_sk));
+    let vk: VerifyingKey = sk.verifying_key();
+
+    let hash = Digest::hash_items(&[tiled_log.log_id.as_bytes(), key_id.as_bytes(), &vk.to_bytes()]);
+    let signature = sk.sign(&hash.to_bytes());
+
+    let create_acc_op = Operation::CreateAccount {
+        id: tiled_log.log_id.clone(),
+        service_id: key_id.to_string(),
+        challenge: ServiceChallengeInput::Signed(signature),
+        key: vk,
+    };
+
+    let mut account = Account::default();
+    let create_acc_tx =
+        account.prepare_transaction(tiled_log.log_id.clone(), create_acc_op, &sk)?;
+
+    debug!(
+        "Submitting transaction to create account {} ({})",
+        tiled_log.log_id, tiled_log.description
+    );
+    prover
+        .clone()
+        .validate_and_queue_update(create_acc_tx.clone())
+        .await?;
+
+    account.process_transaction(&create_acc_tx)?;
+    Ok(account)
+}
+
+/// Mirrors `watch_log`'s polling loop for the static-CT tiled log API:
+/// fetch the log's checkpoint, verify its signature, and feed the
+/// resulting tree head into the same `SetData` flow.
+async fn watch_tiled_log(
+    tiled_log: TiledLog,
+    prover: Arc<Prover>,
+    service_sk: SigningKey,
+    account: &mut Account,
+    interval: Duration,
+    store: Arc<dyn MonitorStore>,
+    checkpoint: Option<LogCheckpoint>,
+) -> Result<()> {
+    let log_vk = VerifyingKey::from_algorithm_and_der(Secp256r1, &tiled_log.key)?;
+    let client = TiledLogClient::new();
+
+    let mut last_tree_head = checkpoint.map(|c| c.root_hash).unwrap_or([0u8; 32]);
+    let mut last_tree_size = checkpoint.map(|c| c.tree_size).unwrap_or(0);
+
+    loop {
+        match client.fetch_checkpoint(&tiled_log.monitoring_url).await {
+            Ok(head) => {
+                if let Err(e) = verify_checkpoint(&head, &log_vk) {
+                    error!(
+                        "Checkpoint signature verification failed for {}: {}",
+                        tiled_log.description, e
+                    );
+                } else if head.tree_size < last_tree_size {
+                    error!(
+                        "Tiled log {} tree size went backwards: {} -> {}",
+                        tiled_log.description, last_tree_size, head.tree_size
+                    );
+                } else if head.root_hash != last_tree_head {
                     last_tree_head = head.root_hash;
-                    debug!("{}: {}", log.description, BASE64.encode(head.root_hash));
+                    last_tree_size = head.tree_size;
+
+                    if head.signature().len() <= 4 {
+                        error!("Checkpoint signature for {} is too short", tiled_log.description);
+                    } else {
+                        let signature = Signature::from_algorithm_and_der(
+                            Secp256r1,
+                            &head.signature()[4..],
+                        )?;
+
+                        let update_op = Operation::SetData {
+                            data: head.signed_body(),
+                            data_signature: SignatureBundle {
+                                verifying_key: log_vk.clone(),
+                                signature,
+                            },
+                        };
+
+                        let update_tx = account.prepare_transaction(
+                            tiled_log.log_id.to_string(),
+                            update_op,
+                            &service_sk,
+                        )?;
+
+                        loop {
+                            match prover
+                                .clone()
+                                .validate_and_queue_update(update_tx.clone())
+                                .await
+                            {
+                                Ok(_) => {
+                                    debug!(
+                                        "{}: {:?}",
+                                        tiled_log.description,
+                                        BASE64.encode(head.root_hash)
+                                    );
+                                    break;
+                                }
+                                Err(e) => {
+                                    error!(
+                                        "Error posting to prism {}: {}",
+                                        tiled_log.monitoring_url, e
+                                    );
+                                    tokio::time::sleep(Duration::from_secs(1)).await;
+                                }
+                            };
+                        }
+                        account.process_transaction(&update_tx)?;
+
+                        let checkpoint = LogCheckpoint {
+                            root_hash: head.root_hash,
+                            tree_size: head.tree_size,
+                            timestamp: 0,
+                        };
+                        if let Err(e) = store.save_checkpoint(&tiled_log.log_id, &checkpoint).await
+                        {
+                            error!(
+                                "Error persisting checkpoint for {}: {}",
+                                tiled_log.description, e
+                            );
+                        }
+                    }
                 }
             }
+            Err(e) => {
+                error!("Error fetching checkpoint for {}: {}", tiled_log.description, e);
+            }
         }
 
         tokio::time::sleep(interval).await;
     }
 }
 
+/// Catches up on certificates logged while the process was down. Resuming
+/// via `CTClient::new_from_latest_th` always starts tracking from the log's
+/// *current* tip, so anything appended between `last_tree_size` and that
+/// tip would otherwise never reach the watchlist callback. Fetches the
+/// missing leaves directly via `get-entries` and matches them before the
+/// regular polling loop starts.
+async fn backfill_watchlist_gap(
+    log: &Log,
+    last_tree_size: u64,
+    watchlist: &Watchlist,
+    watchlist_account: &AsyncMutex<Account>,
+    service_sk: &SigningKey,
+    prover: &Arc<Prover>,
+) -> Result<()> {
+    let current_size = fetch_latest_tree_size(&log.url).await?;
+    if current_size <= last_tree_size {
+        return Ok(());
+    }
+
+    debug!(
+        "{}: backfilling {} entries missed while offline",
+        log.description,
+        current_size - last_tree_size
+    );
+
+    for (leaf_index, leaf_input) in fetch_entries(&log.url, last_tree_size, current_size).await? {
+        if let Err(e) = record_watchlist_matches(
+            log,
+            leaf_index,
+            &leaf_input,
+            watchlist,
+            watchlist_account,
+            service_sk,
+            prover,
+        )
+        .await
+        {
+            warn!(
+                "Error matching backfilled leaf {} of {} against watchlist: {}",
+                leaf_index, log.description, e
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a single newly-appended leaf, checks its names against the
+/// watchlist, and records a match as a `SetData` op under the shared
+/// watchlist account.
+#[allow(clippy::too_many_arguments)]
+async fn record_watchlist_matches(
+    log: &Log,
+    leaf_index: u64,
+    leaf_input: &[u8],
+    watchlist: &Watchlist,
+    watchlist_account: &AsyncMutex<Account>,
+    service_sk: &SigningKey,
+    prover: &Arc<Prover>,
+) -> Result<()> {
+    let names = extract_names(leaf_input)?;
+
+    let Some(matched_domain) = names.all_names().find_map(|name| {
+        watchlist
+            .matching_domain(name)
+            .map(|domain| domain.to_string())
+    }) else {
+        return Ok(());
+    };
+
+    info!(
+        "Watchlist match in {} (leaf {}): {}",
+        log.description, leaf_index, matched_domain
+    );
+
+    let event = WatchlistEvent {
+        log_id: log.log_id.clone(),
+        log_description: log.description.clone(),
+        leaf_index,
+        matched_domain,
+        common_name: names.common_name,
+        sans: names.sans,
+    };
+
+    let data = serde_json::to_vec(&event)?;
+    let signature = service_sk.sign(&data);
+
+    let update_op = Operation::SetData {
+        data,
+        data_signature: SignatureBundle {
+            verifying_key: service_sk.verifying_key(),
+            signature,
+        },
+    };
+
+    let mut account = watchlist_account.lock().await;
+    let update_tx =
+        account.prepare_transaction(WATCHLIST_ACCOUNT_ID.to_string(), update_op, service_sk)?;
+
+    loop {
+        match prover.clone().validate_and_queue_update(update_tx.clone()).await {
+            Ok(_) => break,
+            Err(e) => {
+                error!("Error posting watchlist match to prism: {}", e);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+    account.process_transaction(&update_tx)?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use ctclient::SignedTreeHead;
@@ -1,14 +1,22 @@
+mod config;
+mod consistency;
+mod leaf_entry;
 mod log_list;
 mod log_monitoring;
+mod monitor_store;
+mod watchlist;
 
 use anyhow::{anyhow, Result};
+use config::StorageConfig;
 use keystore_rs::{KeyChain, KeyStore};
 use log::debug;
 use log_monitoring::monitor_operators;
+use monitor_store::{InMemoryMonitorStore, MonitorStore, RedbMonitorStore};
+use watchlist::Watchlist;
 use prism_da::{memory::InMemoryDataAvailabilityLayer, DataAvailabilityLayer};
 use prism_keys::SigningKey;
 use prism_storage::inmemory::InMemoryDatabase;
-use std::{sync::Arc, time::Duration};
+use std::sync::Arc;
 use tokio::spawn;
 
 use prism_prover::{webserver::WebServerConfig, Config, Prover};
@@ -17,17 +25,16 @@ pub static CT_SERVICE_KEY_ID: &str = "ct_service";
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    std::env::set_var(
-            "RUST_LOG",
-            "DEBUG,ctclient::internal=off,reqwest=off,hyper=off,tracing=off,sp1_stark=info,jmt=off,p3_dft=off,p3_fri=off,sp1_core_executor=info,sp1_recursion_program=info,p3_merkle_tree=off,sp1_recursion_compiler=off,sp1_core_machine=off",
-        );
+    let config = Arc::new(config::load()?);
+
+    std::env::set_var("RUST_LOG", &config.log_filter);
     pretty_env_logger::init();
 
     let db = InMemoryDatabase::new();
     let (da_layer, _, _) = InMemoryDataAvailabilityLayer::new(5);
 
     let keystore_sk = KeyChain
-        .get_signing_key(CT_SERVICE_KEY_ID)
+        .get_signing_key(&config.keystore_key_id)
         .map_err(|e| anyhow!("Error getting key from store: {}", e))?;
 
     let sk = SigningKey::Ed25519(Box::new(keystore_sk.clone()));
@@ -36,9 +43,9 @@ async fn main() -> Result<()> {
         prover: true,
         batcher: true,
         webserver: WebServerConfig {
-            enabled: true,
-            host: "127.0.0.1".to_string(),
-            port: 50524,
+            enabled: config.webserver.enabled,
+            host: config.webserver.host.clone(),
+            port: config.webserver.port,
         },
         signing_key: sk.clone(),
         verifying_key: sk.verifying_key(),
@@ -62,16 +69,24 @@ async fn main() -> Result<()> {
         }
     });
 
-    let operators = vec![
-        "Google".to_string(),
-        "Cloudflare".to_string(),
-        "DigiCert".to_string(),
-        "Sectigo".to_string(),
-        "Let's Encrypt".to_string(),
-    ];
-    let interval = Duration::from_secs(60);
+    let store: Arc<dyn MonitorStore> = match &config.storage {
+        StorageConfig::Memory => Arc::new(InMemoryMonitorStore::new()),
+        StorageConfig::Redb { path } => match RedbMonitorStore::open(path) {
+            Ok(store) => Arc::new(store),
+            Err(e) => {
+                log::warn!(
+                    "Error opening durable monitor store at {}, falling back to in-memory: {}",
+                    path,
+                    e
+                );
+                Arc::new(InMemoryMonitorStore::new())
+            }
+        },
+    };
+
+    let watchlist = Arc::new(Watchlist::new(config.watched_domains.clone()));
 
-    monitor_operators(operators, interval, sk, prover).await?;
+    monitor_operators(config, sk, prover, store, watchlist).await?;
 
     tokio::select! {
         _ = runner_handle => {
@@ -0,0 +1,48 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::MonitorStore;
+
+/// In-memory [`MonitorStore`] used for tests and for operators who don't
+/// need monitoring state to survive a restart.
+#[derive(Default)]
+pub struct InMemoryMonitorStore {
+    blobs: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryMonitorStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MonitorStore for InMemoryMonitorStore {
+    async fn blob_fetch(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self.blobs.lock().unwrap().get(key).cloned())
+    }
+
+    async fn blob_put(&self, key: &str, value: &[u8]) -> Result<()> {
+        self.blobs
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.to_vec());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn blob_fetch_round_trips_a_put_value() {
+        let store = InMemoryMonitorStore::new();
+        assert_eq!(store.blob_fetch("k").await.unwrap(), None);
+
+        store.blob_put("k", b"v1").await.unwrap();
+        assert_eq!(store.blob_fetch("k").await.unwrap(), Some(b"v1".to_vec()));
+    }
+}
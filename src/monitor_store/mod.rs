@@ -0,0 +1,81 @@
+mod memory;
+mod redb_store;
+
+pub use memory::InMemoryMonitorStore;
+pub use redb_store::RedbMonitorStore;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::log_list::LogList;
+
+/// Checkpoint of the last verified signed tree head for a single CT log.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LogCheckpoint {
+    pub root_hash: [u8; 32],
+    pub tree_size: u64,
+    pub timestamp: u64,
+}
+
+/// Snapshot of the cached log list, persisted so a restart inside
+/// `cache_duration` reuses it instead of re-fetching, and a failed fetch can
+/// fall back to it. The HTTP validators let the next fetch be a conditional `If-None-Match` /
+/// `If-Modified-Since` request instead of a full re-download.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogListSnapshot {
+    pub log_list: LogList,
+    pub fetched_at: u64,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+/// Durable backing store for monitor state.
+///
+/// Mirrors the "storage behind a trait" split used elsewhere in the prism
+/// stack: a small generic blob interface that every backend implements,
+/// with typed helpers (see [`MonitorStoreExt`]) layered on top so callers
+/// never touch serialization or key naming directly.
+#[async_trait]
+pub trait MonitorStore: Send + Sync {
+    async fn blob_fetch(&self, key: &str) -> Result<Option<Vec<u8>>>;
+    async fn blob_put(&self, key: &str, value: &[u8]) -> Result<()>;
+}
+
+fn checkpoint_key(log_id: &str) -> String {
+    format!("checkpoint/{log_id}")
+}
+
+const LOG_LIST_SNAPSHOT_KEY: &str = "log_list/snapshot";
+
+/// Typed convenience methods built on top of [`MonitorStore::blob_fetch`] /
+/// [`MonitorStore::blob_put`], implemented for every `MonitorStore` so
+/// backends only ever have to deal with bytes.
+#[async_trait]
+pub trait MonitorStoreExt: MonitorStore {
+    async fn load_checkpoint(&self, log_id: &str) -> Result<Option<LogCheckpoint>> {
+        match self.blob_fetch(&checkpoint_key(log_id)).await? {
+            Some(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save_checkpoint(&self, log_id: &str, checkpoint: &LogCheckpoint) -> Result<()> {
+        let bytes = serde_json::to_vec(checkpoint)?;
+        self.blob_put(&checkpoint_key(log_id), &bytes).await
+    }
+
+    async fn load_log_list_snapshot(&self) -> Result<Option<LogListSnapshot>> {
+        match self.blob_fetch(LOG_LIST_SNAPSHOT_KEY).await? {
+            Some(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save_log_list_snapshot(&self, snapshot: &LogListSnapshot) -> Result<()> {
+        let bytes = serde_json::to_vec(snapshot)?;
+        self.blob_put(LOG_LIST_SNAPSHOT_KEY, &bytes).await
+    }
+}
+
+impl<T: MonitorStore + ?Sized> MonitorStoreExt for T {}
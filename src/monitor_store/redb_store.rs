@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use redb::{Database, ReadableTable, TableDefinition};
+
+use super::MonitorStore;
+
+const BLOBS_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("monitor_blobs");
+
+/// Durable [`MonitorStore`] backed by an embedded `redb` database, so
+/// checkpoints and the log list snapshot survive process restarts.
+pub struct RedbMonitorStore {
+    db: Database,
+}
+
+impl RedbMonitorStore {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let db = Database::create(path)?;
+
+        // Make sure the table exists so the first `blob_fetch` doesn't have
+        // to special-case "table not created yet".
+        let write_txn = db.begin_write()?;
+        {
+            write_txn.open_table(BLOBS_TABLE)?;
+        }
+        write_txn.commit()?;
+
+        Ok(Self { db })
+    }
+}
+
+#[async_trait]
+impl MonitorStore for RedbMonitorStore {
+    async fn blob_fetch(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let read_txn = self.db.begin_read()?;
+        let table = read_txn.open_table(BLOBS_TABLE)?;
+        Ok(table.get(key)?.map(|value| value.value().to_vec()))
+    }
+
+    async fn blob_put(&self, key: &str, value: &[u8]) -> Result<()> {
+        let write_txn = self.db.begin_write()?;
+        {
+            let mut table = write_txn.open_table(BLOBS_TABLE)?;
+            table.insert(key, value)?;
+        }
+        write_txn.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn blob_persists_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("monitor.redb");
+
+        {
+            let store = RedbMonitorStore::open(&db_path).unwrap();
+            assert_eq!(store.blob_fetch("k").await.unwrap(), None);
+            store.blob_put("k", b"v1").await.unwrap();
+            assert_eq!(store.blob_fetch("k").await.unwrap(), Some(b"v1".to_vec()));
+        }
+
+        // Reopening the same file should see the previously written blob.
+        let store = RedbMonitorStore::open(&db_path).unwrap();
+        assert_eq!(store.blob_fetch("k").await.unwrap(), Some(b"v1".to_vec()));
+
+        store.blob_put("k", b"v2").await.unwrap();
+        assert_eq!(store.blob_fetch("k").await.unwrap(), Some(b"v2".to_vec()));
+    }
+}
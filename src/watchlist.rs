@@ -0,0 +1,76 @@
+//! Matches certificate SANs/CNs seen in CT logs against a configured set of
+//! domains an operator cares about.
+
+use serde::{Deserialize, Serialize};
+
+/// A structured "cert seen for a watched domain" event, recorded as a
+/// `SetData` operation under the shared watchlist account so downstream
+/// consumers get a tamper-evident feed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchlistEvent {
+    pub log_id: String,
+    pub log_description: String,
+    pub leaf_index: u64,
+    pub matched_domain: String,
+    pub common_name: Option<String>,
+    pub sans: Vec<String>,
+}
+
+/// A configurable set of watched domains, supporting exact matches and
+/// suffix (subdomain) matches.
+///
+/// `example.com` matches `example.com` itself and any subdomain such as
+/// `www.example.com`, mirroring how operators usually phrase "certs for my
+/// domain or anything under it".
+#[derive(Debug, Clone, Default)]
+pub struct Watchlist {
+    domains: Vec<String>,
+}
+
+impl Watchlist {
+    pub fn new(domains: Vec<String>) -> Self {
+        Self {
+            domains: domains.into_iter().map(|d| d.trim_end_matches('.').to_lowercase()).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+
+    /// Returns the watched domain that `name` matches, if any.
+    pub fn matching_domain(&self, name: &str) -> Option<&str> {
+        let name = name.trim_end_matches('.').to_lowercase();
+        self.domains
+            .iter()
+            .find(|watched| Self::domain_matches(watched, &name))
+            .map(String::as_str)
+    }
+
+    fn domain_matches(watched: &str, name: &str) -> bool {
+        name == watched || name.ends_with(&format!(".{watched}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_exact_and_subdomains() {
+        let watchlist = Watchlist::new(vec!["example.com".to_string()]);
+        assert_eq!(watchlist.matching_domain("example.com"), Some("example.com"));
+        assert_eq!(
+            watchlist.matching_domain("www.example.com"),
+            Some("example.com")
+        );
+        assert_eq!(watchlist.matching_domain("notexample.com"), None);
+        assert_eq!(watchlist.matching_domain("example.org"), None);
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        let watchlist = Watchlist::new(vec!["Example.COM".to_string()]);
+        assert_eq!(watchlist.matching_domain("WWW.example.com"), Some("example.com"));
+    }
+}